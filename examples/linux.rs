@@ -1,7 +1,8 @@
-use linux_embedded_hal::I2cdev;
-use veml6070::Veml6070;
-
+#[cfg(not(feature = "async"))]
 fn main() {
+    use linux_embedded_hal::I2cdev;
+    use veml6070::Veml6070;
+
     let dev = I2cdev::new("/dev/i2c-1").unwrap();
     let mut uv_light_sensor = Veml6070::new(dev);
     // initialization step is necessary
@@ -10,3 +11,8 @@ fn main() {
     let reading = uv_light_sensor.read_uv().unwrap();
     println!("UV reading: {}", reading);
 }
+
+// `linux-embedded-hal` does not implement `embedded-hal-async` yet, so this
+// blocking example has nothing to show with the `async` feature enabled.
+#[cfg(feature = "async")]
+fn main() {}
@@ -9,6 +9,20 @@
 //! - Set the integration time
 //! - Enable/disable ACK signal
 //! - Set ACK threshold value
+//! - Classify a UV reading into a UVA risk level
+//! - Perform a one-shot measurement
+//! - Configure a custom Rset resistor value
+//! - Compose and apply a full configuration in a single I²C transaction
+//!
+//! ## Optional async support
+//!
+//! Enabling the `async` feature switches this driver's I²C-touching methods
+//! to `async fn`s built on [`embedded-hal-async`]'s `i2c::I2c` trait, for
+//! use with async executors such as Embassy or RTIC. The async API mirrors
+//! the blocking one method-for-method, and the two cannot be enabled at
+//! the same time.
+//!
+//! [`embedded-hal-async`]: https://github.com/rust-embedded/embedded-hal
 //!
 //! ## The device
 //! VEML6070 is an advanced ultraviolet (UV) light sensor with I2C protocol
@@ -45,9 +59,13 @@
 //! ### Read UV
 //!
 //! Import this crate and an `embedded_hal` implementation, then instantiate
-//! the device:
+//! the device. The examples below use the blocking API; they are compiled
+//! but not run, and with the `async` feature enabled they are ignored
+//! (neither compiled nor run), since `linux-embedded-hal` does not
+//! implement `embedded-hal-async`:
 //!
-//! ```no_run
+#![cfg_attr(not(feature = "async"), doc = "```no_run")]
+#![cfg_attr(feature = "async", doc = "```ignore")]
 //! use linux_embedded_hal::I2cdev;
 //! use veml6070::Veml6070;
 //!
@@ -61,7 +79,8 @@
 //!
 //! ### Set integration time
 //!
-//! ```no_run
+#![cfg_attr(not(feature = "async"), doc = "```no_run")]
+#![cfg_attr(feature = "async", doc = "```ignore")]
 //! use linux_embedded_hal::I2cdev;
 //! use veml6070::{Veml6070, IntegrationTime};
 //!
@@ -75,7 +94,8 @@
 //!
 //! ### Enable ACK and set a threshold of 145 steps
 //!
-//! ```no_run
+#![cfg_attr(not(feature = "async"), doc = "```no_run")]
+#![cfg_attr(feature = "async", doc = "```ignore")]
 //! use linux_embedded_hal::I2cdev;
 //! use veml6070::{Veml6070, AckThreshold};
 //!
@@ -86,13 +106,82 @@
 //! uv_light_sensor.enable().unwrap();
 //! uv_light_sensor.enable_ack_with_threshold(AckThreshold::Steps145).unwrap();
 //! ```
+//!
+//! ### Read the UVA risk level
+//!
+#![cfg_attr(not(feature = "async"), doc = "```no_run")]
+#![cfg_attr(feature = "async", doc = "```ignore")]
+//! use linux_embedded_hal::I2cdev;
+//! use veml6070::Veml6070;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut uv_light_sensor = Veml6070::new(dev);
+//! // initialization step is necessary
+//! uv_light_sensor.init().unwrap();
+//! uv_light_sensor.enable().unwrap();
+//! let risk_level = uv_light_sensor.read_uv_risk_level().unwrap();
+//! println!("UVA risk level: {:?}", risk_level);
+//! ```
+//!
+//! ### Perform a one-shot measurement
+//!
+#![cfg_attr(not(feature = "async"), doc = "```no_run")]
+#![cfg_attr(feature = "async", doc = "```ignore")]
+//! use linux_embedded_hal::{Delay, I2cdev};
+//! use veml6070::Veml6070;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut uv_light_sensor = Veml6070::new(dev);
+//! // initialization step is necessary
+//! uv_light_sensor.init().unwrap();
+//! let mut delay = Delay {};
+//! let _uv_reading = uv_light_sensor.measure(&mut delay).unwrap();
+//! ```
+//!
+//! ### Use a custom Rset resistor value
+//!
+#![cfg_attr(not(feature = "async"), doc = "```no_run")]
+#![cfg_attr(feature = "async", doc = "```ignore")]
+//! use linux_embedded_hal::I2cdev;
+//! use veml6070::Veml6070;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! // board using a 600 kΩ Rset resistor
+//! let mut uv_light_sensor = Veml6070::with_rset(dev, 600_000);
+//! uv_light_sensor.init().unwrap();
+//! ```
+//!
+//! ### Apply a full configuration at once
+//!
+#![cfg_attr(not(feature = "async"), doc = "```no_run")]
+#![cfg_attr(feature = "async", doc = "```ignore")]
+//! use linux_embedded_hal::I2cdev;
+//! use veml6070::{AckThreshold, Config, IntegrationTime, Veml6070};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut uv_light_sensor = Veml6070::new(dev);
+//! uv_light_sensor.init().unwrap();
+//! let config = Config::new()
+//!     .integration_time(IntegrationTime::T2)
+//!     .ack(true)
+//!     .ack_threshold(AckThreshold::Steps145);
+//! uv_light_sensor.apply_config(&config).unwrap();
+//! ```
 
 #![deny(unsafe_code)]
 #![deny(missing_docs)]
 #![no_std]
 
+#[cfg(not(feature = "async"))]
+use embedded_hal::blocking::delay::DelayMs;
+#[cfg(not(feature = "async"))]
 use embedded_hal::blocking::i2c;
 
+#[cfg(feature = "async")]
+use embedded_hal_async::delay::DelayNs;
+#[cfg(feature = "async")]
+use embedded_hal_async::i2c::I2c;
+
 /// All possible errors in this crate
 #[derive(Debug)]
 pub enum Error<E> {
@@ -101,7 +190,7 @@ pub enum Error<E> {
 }
 
 /// Integration time
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum IntegrationTime {
     /// Half T
     HalfT,
@@ -114,7 +203,7 @@ pub enum IntegrationTime {
 }
 
 /// ACK threshold
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AckThreshold {
     /// 102 steps
     Steps102,
@@ -122,10 +211,179 @@ pub enum AckThreshold {
     Steps145,
 }
 
+/// UVA risk level, as defined in the VEML6070 application note for the
+/// reference design (Rset ≈ 270 kΩ).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RiskLevel {
+    /// Low
+    Low,
+    /// Moderate
+    Moderate,
+    /// High
+    High,
+    /// Very high
+    VeryHigh,
+    /// Extreme
+    Extreme,
+}
+
+/// Command register configuration.
+///
+/// This lets callers compose all settings at once and apply them with a
+/// single I²C transaction through [`Veml6070::apply_config()`], instead of
+/// replaying the individual setter calls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    integration_time: IntegrationTime,
+    ack: bool,
+    ack_threshold: AckThreshold,
+    shutdown: bool,
+}
+
+impl Default for Config {
+    /// The power-on-reset configuration: `HalfT` integration time, ACK
+    /// disabled with a threshold of 102 steps, and the device enabled.
+    fn default() -> Self {
+        Config {
+            integration_time: IntegrationTime::HalfT,
+            ack: false,
+            ack_threshold: AckThreshold::Steps102,
+            shutdown: false,
+        }
+    }
+}
+
+impl Config {
+    /// Create a new configuration with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the integration time.
+    pub fn integration_time(mut self, it: IntegrationTime) -> Self {
+        self.integration_time = it;
+        self
+    }
+
+    /// Enable or disable the ACK signal.
+    pub fn ack(mut self, ack: bool) -> Self {
+        self.ack = ack;
+        self
+    }
+
+    /// Set the ACK threshold.
+    pub fn ack_threshold(mut self, threshold: AckThreshold) -> Self {
+        self.ack_threshold = threshold;
+        self
+    }
+
+    /// Enable or disable shutdown mode.
+    pub fn shutdown(mut self, shutdown: bool) -> Self {
+        self.shutdown = shutdown;
+        self
+    }
+
+    fn to_cmd(&self) -> u8 {
+        // The reserved bit is always set, matching the baseline `new()` and
+        // `init()` use (`cmd: 0x02`).
+        let mut cmd = encode_integration_time(BitFlags::RESERVED, &self.integration_time);
+        if self.ack {
+            cmd |= BitFlags::ACK;
+        }
+        if let AckThreshold::Steps145 = self.ack_threshold {
+            cmd |= BitFlags::ACK_THD;
+        }
+        if self.shutdown {
+            cmd |= BitFlags::SHUTDOWN;
+        }
+        cmd
+    }
+
+    fn from_cmd(cmd: u8) -> Self {
+        Config {
+            integration_time: decode_integration_time(cmd),
+            ack: cmd & BitFlags::ACK != 0,
+            ack_threshold: if cmd & BitFlags::ACK_THD != 0 {
+                AckThreshold::Steps145
+            } else {
+                AckThreshold::Steps102
+            },
+            shutdown: cmd & BitFlags::SHUTDOWN != 0,
+        }
+    }
+}
+
+fn decode_integration_time(cmd: u8) -> IntegrationTime {
+    match (cmd & BitFlags::IT0 != 0, cmd & BitFlags::IT1 != 0) {
+        (false, false) => IntegrationTime::HalfT,
+        (true, false) => IntegrationTime::T1,
+        (false, true) => IntegrationTime::T2,
+        (true, true) => IntegrationTime::T4,
+    }
+}
+
+/// Clear the IT0/IT1 bits and set them for `it`, shared by the blocking and
+/// async `set_integration_time()` so the bit math can only be wrong once.
+fn encode_integration_time(cmd: u8, it: &IntegrationTime) -> u8 {
+    let cmd = cmd & !BitFlags::IT0 & !BitFlags::IT1;
+    match it {
+        IntegrationTime::HalfT => cmd,
+        IntegrationTime::T1 => cmd | BitFlags::IT0,
+        IntegrationTime::T2 => cmd | BitFlags::IT1,
+        IntegrationTime::T4 => cmd | BitFlags::IT0 | BitFlags::IT1,
+    }
+}
+
+/// Default Rset resistor value (270 kΩ), as used in the reference design
+/// the application note thresholds and timings are given for.
+const DEFAULT_RSET_OHMS: u32 = 270_000;
+
+/// Classify a raw UV reading into a [`RiskLevel`], taking into account the
+/// integration time it was read with and the Rset resistor value of the
+/// circuit, in ohms.
+///
+/// The raw value is first normalized to the 1T, 270 kΩ basis (the
+/// application note thresholds are given for that configuration) before
+/// being compared against the reference thresholds.
+///
+/// `rset_ohms` must be nonzero, since it is used as a divisor; a value of
+/// `0` is clamped up to `1` rather than panicking.
+pub fn get_uva_risk_level(raw: u16, it: IntegrationTime, rset_ohms: u32) -> RiskLevel {
+    match normalize_to_reference(raw, &it, rset_ohms) {
+        0..=560 => RiskLevel::Low,
+        561..=1120 => RiskLevel::Moderate,
+        1121..=1494 => RiskLevel::High,
+        1495..=2054 => RiskLevel::VeryHigh,
+        _ => RiskLevel::Extreme,
+    }
+}
+
+fn normalize_to_reference(raw: u16, it: &IntegrationTime, rset_ohms: u32) -> u64 {
+    // Widen to `u64` before multiplying: `normalized_it * DEFAULT_RSET_OHMS`
+    // can exceed `u32::MAX` for entirely ordinary `raw`/`rset_ohms` values.
+    // Clamp `rset_ohms` to `1` so a `0` value can't cause a divide-by-zero
+    // panic; see the nonzero invariant documented on `get_uva_risk_level`.
+    let normalized_it = u64::from(normalize_to_1t(raw, it));
+    normalized_it * u64::from(DEFAULT_RSET_OHMS) / u64::from(rset_ohms.max(1))
+}
+
+fn normalize_to_1t(raw: u16, it: &IntegrationTime) -> u32 {
+    let raw = u32::from(raw);
+    match it {
+        IntegrationTime::HalfT => raw * 2,
+        IntegrationTime::T1 => raw,
+        IntegrationTime::T2 => raw / 2,
+        IntegrationTime::T4 => raw / 4,
+    }
+}
+
 struct BitFlags;
 
 impl BitFlags {
     const SHUTDOWN: u8 = 0b0000_0001;
+    /// Reserved bit, always set in the default and every derived command
+    /// byte (see `new()`'s and `init()`'s `0x02` baseline).
+    const RESERVED: u8 = 0b0000_0010;
     const IT0: u8 = 0b0000_0100;
     const IT1: u8 = 0b0000_1000;
     const ACK_THD: u8 = 0b0001_0000;
@@ -148,15 +406,37 @@ pub struct Veml6070<I2C> {
     i2c: I2C,
     /// Command register status.
     cmd: u8,
+    /// Rset resistor value of the circuit, in ohms.
+    rset_ohms: u32,
 }
 
+#[cfg(not(feature = "async"))]
 impl<I2C, E> Veml6070<I2C>
 where
     I2C: i2c::Write<Error = E>,
 {
-    /// Create new instance of the VEML6070 device.
+    /// Create new instance of the VEML6070 device, assuming the reference
+    /// Rset resistor value (270 kΩ).
     pub fn new(i2c: I2C) -> Self {
-        Veml6070 { i2c, cmd: 0x02 }
+        Self::with_rset(i2c, DEFAULT_RSET_OHMS)
+    }
+
+    /// Create a new instance of the VEML6070 device for a circuit using a
+    /// custom Rset resistor value, in ohms.
+    ///
+    /// This is necessary to get correct settling times and UVA risk level
+    /// classification on boards that do not use the reference 270 kΩ Rset
+    /// (for example some Adafruit boards use a different value).
+    ///
+    /// `rset_ohms` must be nonzero, since it is later used as a divisor when
+    /// classifying UVA risk levels; a value of `0` is clamped up to `1`
+    /// rather than causing a panic down the line.
+    pub fn with_rset(i2c: I2C, rset_ohms: u32) -> Self {
+        Veml6070 {
+            i2c,
+            cmd: 0x02,
+            rset_ohms: rset_ohms.max(1),
+        }
     }
 
     /// Destroy driver instance, return I²C bus instance.
@@ -178,13 +458,7 @@ where
 
     /// Set integration time.
     pub fn set_integration_time(&mut self, it: IntegrationTime) -> Result<(), Error<E>> {
-        let mut cmd = self.cmd;
-        cmd = match it {
-            IntegrationTime::HalfT => cmd & !BitFlags::IT0 & !BitFlags::IT1,
-            IntegrationTime::T1 => cmd | BitFlags::IT0 & !BitFlags::IT1,
-            IntegrationTime::T2 => cmd & !BitFlags::IT0 | BitFlags::IT1,
-            IntegrationTime::T4 => cmd | BitFlags::IT0 | BitFlags::IT1,
-        };
+        let cmd = encode_integration_time(self.cmd, &it);
         self.write_command(cmd)
     }
 
@@ -217,6 +491,12 @@ where
         self.write_command(handle_ack_threshold_bit(cmd, threshold))
     }
 
+    /// Apply a full [`Config`], writing the composed command byte in a
+    /// single I²C transaction.
+    pub fn apply_config(&mut self, cfg: &Config) -> Result<(), Error<E>> {
+        self.write_command(cfg.to_cmd())
+    }
+
     fn write_command(&mut self, cmd: u8) -> Result<(), Error<E>> {
         self.i2c
             .write(Address::COMMAND, &[cmd])
@@ -226,6 +506,37 @@ where
     }
 }
 
+impl<I2C> Veml6070<I2C> {
+    fn integration_time(&self) -> IntegrationTime {
+        decode_integration_time(self.cmd)
+    }
+
+    /// Settling time in milliseconds for the currently configured
+    /// integration time, scaled for the configured Rset resistor value.
+    ///
+    /// The base refresh time is proportional to Rset, so it is scaled up
+    /// or down from the reference design (270 kΩ) values and rounded up,
+    /// so the wait always covers at least one full period.
+    fn settling_time_ms(&self) -> u32 {
+        let base_ms: u64 = match self.integration_time() {
+            IntegrationTime::HalfT => 63,
+            IntegrationTime::T1 => 125,
+            IntegrationTime::T2 => 250,
+            IntegrationTime::T4 => 500,
+        };
+        // Widen to `u64`: `base_ms * self.rset_ohms` can exceed `u32::MAX`
+        // for large-but-plausible Rset values.
+        let reference_ohms = u64::from(DEFAULT_RSET_OHMS);
+        let scaled_ms = (base_ms * u64::from(self.rset_ohms)).div_ceil(reference_ohms);
+        scaled_ms.min(u64::from(u32::MAX)) as u32
+    }
+
+    /// Get the currently cached command register configuration.
+    pub fn get_config(&self) -> Config {
+        Config::from_cmd(self.cmd)
+    }
+}
+
 fn handle_ack_threshold_bit(cmd: u8, threshold: AckThreshold) -> u8 {
     match threshold {
         AckThreshold::Steps102 => cmd & !BitFlags::ACK_THD,
@@ -233,6 +544,7 @@ fn handle_ack_threshold_bit(cmd: u8, threshold: AckThreshold) -> u8 {
     }
 }
 
+#[cfg(not(feature = "async"))]
 impl<I2C, E> Veml6070<I2C>
 where
     I2C: i2c::Read<Error = E>,
@@ -258,8 +570,20 @@ where
             .map_err(Error::I2C)?;
         Ok(u16::from(msb[0]) << 8 | u16::from(lsb[0]))
     }
+
+    /// Read the UV sensor and classify the reading into a [`RiskLevel`] in
+    /// one call.
+    pub fn read_uv_risk_level(&mut self) -> Result<RiskLevel, Error<E>> {
+        let raw = self.read_uv()?;
+        Ok(get_uva_risk_level(
+            raw,
+            self.integration_time(),
+            self.rset_ohms,
+        ))
+    }
 }
 
+#[cfg(not(feature = "async"))]
 impl<I2C, E> Veml6070<I2C>
 where
     I2C: i2c::Read<Error = E> + i2c::Write<Error = E>,
@@ -270,4 +594,189 @@ where
         let cmd = 0x02; // default setting
         self.write_command(cmd)
     }
+
+    /// Perform a one-shot measurement.
+    ///
+    /// This wakes the sensor up, waits for a full conversion at the
+    /// currently configured integration time, reads the value and puts the
+    /// sensor back into shutdown mode to save power.
+    pub fn measure<D: DelayMs<u32>>(&mut self, delay: &mut D) -> Result<u16, Error<E>> {
+        self.enable()?;
+        delay.delay_ms(self.settling_time_ms());
+        let reading = self.read_uv();
+        // Always attempt to put the sensor back into shutdown mode, even if
+        // the read above failed, so a failed measurement can't leave the
+        // sensor powered on.
+        let _ = self.disable();
+        reading
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I2C> Veml6070<I2C>
+where
+    I2C: I2c,
+{
+    /// Create new instance of the VEML6070 device, assuming the reference
+    /// Rset resistor value (270 kΩ).
+    pub fn new(i2c: I2C) -> Self {
+        Self::with_rset(i2c, DEFAULT_RSET_OHMS)
+    }
+
+    /// Create a new instance of the VEML6070 device for a circuit using a
+    /// custom Rset resistor value, in ohms.
+    ///
+    /// This is necessary to get correct settling times and UVA risk level
+    /// classification on boards that do not use the reference 270 kΩ Rset
+    /// (for example some Adafruit boards use a different value).
+    ///
+    /// `rset_ohms` must be nonzero, since it is later used as a divisor when
+    /// classifying UVA risk levels; a value of `0` is clamped up to `1`
+    /// rather than causing a panic down the line.
+    pub fn with_rset(i2c: I2C, rset_ohms: u32) -> Self {
+        Veml6070 {
+            i2c,
+            cmd: 0x02,
+            rset_ohms: rset_ohms.max(1),
+        }
+    }
+
+    /// Destroy driver instance, return I²C bus instance.
+    pub fn destroy(self) -> I2C {
+        self.i2c
+    }
+
+    /// Enable the sensor.
+    pub async fn enable(&mut self) -> Result<(), Error<I2C::Error>> {
+        let cmd = self.cmd;
+        self.write_command(cmd & !BitFlags::SHUTDOWN).await
+    }
+
+    /// Disable the sensor (shutdown).
+    pub async fn disable(&mut self) -> Result<(), Error<I2C::Error>> {
+        let cmd = self.cmd;
+        self.write_command(cmd | BitFlags::SHUTDOWN).await
+    }
+
+    /// Set integration time.
+    pub async fn set_integration_time(
+        &mut self,
+        it: IntegrationTime,
+    ) -> Result<(), Error<I2C::Error>> {
+        let cmd = encode_integration_time(self.cmd, &it);
+        self.write_command(cmd).await
+    }
+
+    /// Enable the ACK signal.
+    ///
+    /// *Note:* The ACK must be cleared every time after it has fired with `clear_ack()`.
+    pub async fn enable_ack(&mut self) -> Result<(), Error<I2C::Error>> {
+        let cmd = self.cmd;
+        self.write_command(cmd | BitFlags::ACK).await
+    }
+
+    /// Disable the ACK signal.
+    pub async fn disable_ack(&mut self) -> Result<(), Error<I2C::Error>> {
+        let cmd = self.cmd;
+        self.write_command(cmd & !BitFlags::ACK).await
+    }
+
+    /// Set ACK threshold.
+    pub async fn set_ack_threshold(
+        &mut self,
+        threshold: AckThreshold,
+    ) -> Result<(), Error<I2C::Error>> {
+        let cmd = self.cmd;
+        self.write_command(handle_ack_threshold_bit(cmd, threshold))
+            .await
+    }
+
+    /// Enable the ACK signal and set the ACK threshold at once.
+    ///
+    /// *Note:* The ACK must be cleared every time after it has fired with `clear_ack()`.
+    pub async fn enable_ack_with_threshold(
+        &mut self,
+        threshold: AckThreshold,
+    ) -> Result<(), Error<I2C::Error>> {
+        let mut cmd = self.cmd;
+        cmd |= BitFlags::ACK;
+        self.write_command(handle_ack_threshold_bit(cmd, threshold))
+            .await
+    }
+
+    /// Apply a full [`Config`], writing the composed command byte in a
+    /// single I²C transaction.
+    pub async fn apply_config(&mut self, cfg: &Config) -> Result<(), Error<I2C::Error>> {
+        self.write_command(cfg.to_cmd()).await
+    }
+
+    /// Clear ACK status.
+    ///
+    /// *Note:* The ACK status must be cleared every time after it has fired.
+    /// Other registers will be blocked otherwise.
+    pub async fn clear_ack(&mut self) -> Result<(), Error<I2C::Error>> {
+        let mut buffer = [0];
+        self.i2c
+            .read(Address::ARA, &mut buffer)
+            .await
+            .map_err(Error::I2C)
+    }
+
+    /// Read the UV sensor.
+    pub async fn read_uv(&mut self) -> Result<u16, Error<I2C::Error>> {
+        let mut msb = [0];
+        let mut lsb = [0];
+        self.i2c
+            .read(Address::DATA_MSB, &mut msb)
+            .await
+            .map_err(Error::I2C)?;
+        self.i2c
+            .read(Address::DATA_LSB, &mut lsb)
+            .await
+            .map_err(Error::I2C)?;
+        Ok(u16::from(msb[0]) << 8 | u16::from(lsb[0]))
+    }
+
+    /// Read the UV sensor and classify the reading into a [`RiskLevel`] in
+    /// one call.
+    pub async fn read_uv_risk_level(&mut self) -> Result<RiskLevel, Error<I2C::Error>> {
+        let raw = self.read_uv().await?;
+        Ok(get_uva_risk_level(
+            raw,
+            self.integration_time(),
+            self.rset_ohms,
+        ))
+    }
+
+    /// Initialize and clear ACK.
+    pub async fn init(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.clear_ack().await?;
+        let cmd = 0x02; // default setting
+        self.write_command(cmd).await
+    }
+
+    /// Perform a one-shot measurement.
+    ///
+    /// This wakes the sensor up, waits for a full conversion at the
+    /// currently configured integration time, reads the value and puts the
+    /// sensor back into shutdown mode to save power.
+    pub async fn measure<D: DelayNs>(&mut self, delay: &mut D) -> Result<u16, Error<I2C::Error>> {
+        self.enable().await?;
+        delay.delay_ms(self.settling_time_ms()).await;
+        let reading = self.read_uv().await;
+        // Always attempt to put the sensor back into shutdown mode, even if
+        // the read above failed, so a failed measurement can't leave the
+        // sensor powered on.
+        let _ = self.disable().await;
+        reading
+    }
+
+    async fn write_command(&mut self, cmd: u8) -> Result<(), Error<I2C::Error>> {
+        self.i2c
+            .write(Address::COMMAND, &[cmd])
+            .await
+            .map_err(Error::I2C)?;
+        self.cmd = cmd;
+        Ok(())
+    }
 }
@@ -0,0 +1,136 @@
+#![cfg(feature = "async")]
+
+use embedded_hal_1::i2c::I2c as BlockingI2c;
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::i2c::{ErrorType, I2c, Operation};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+use futures::executor::block_on;
+use veml6070::{AckThreshold, Config, IntegrationTime, RiskLevel, Veml6070};
+
+struct Address;
+
+impl Address {
+    const ARA: u8 = 0x0C;
+    const COMMAND: u8 = 0x38;
+    const DATA_MSB: u8 = 0x39;
+    const DATA_LSB: u8 = 0x38;
+}
+const DEFAULT_CMD: u8 = 0x02;
+
+/// `embedded-hal-mock` does not provide an async I2C mock, so this adapts
+/// its blocking one to `embedded-hal-async`'s `I2c` trait: every operation
+/// resolves immediately, which is all a mock needs.
+struct AsyncI2cMock(I2cMock);
+
+impl ErrorType for AsyncI2cMock {
+    type Error = <I2cMock as ErrorType>::Error;
+}
+
+impl I2c for AsyncI2cMock {
+    async fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        for op in operations {
+            match op {
+                Operation::Read(buf) => self.0.read(address, buf)?,
+                Operation::Write(buf) => self.0.write(address, buf)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn new(transactions: &[I2cTrans]) -> Veml6070<AsyncI2cMock> {
+    Veml6070::new(AsyncI2cMock(I2cMock::new(transactions)))
+}
+
+fn destroy(dev: Veml6070<AsyncI2cMock>) {
+    dev.destroy().0.done();
+}
+
+/// Minimal no-op `DelayNs`, since `embedded-hal-mock` does not provide an
+/// async delay mock.
+struct NoopDelay;
+
+impl DelayNs for NoopDelay {
+    async fn delay_ns(&mut self, _ns: u32) {}
+}
+
+#[test]
+fn can_clear_ack() {
+    let mut dev = new(&[I2cTrans::read(Address::ARA, vec![0])]);
+    block_on(dev.clear_ack()).unwrap();
+    destroy(dev);
+}
+
+#[test]
+fn can_enable() {
+    let mut dev = new(&[I2cTrans::write(Address::COMMAND, vec![DEFAULT_CMD])]);
+    block_on(dev.enable()).unwrap();
+    destroy(dev);
+}
+
+#[test]
+fn can_disable() {
+    let mut dev = new(&[I2cTrans::write(Address::COMMAND, vec![DEFAULT_CMD | 1])]);
+    block_on(dev.disable()).unwrap();
+    destroy(dev);
+}
+
+macro_rules! it_test {
+    ( $test_name:ident, $it:expr, $expected:expr ) => {
+        #[test]
+        fn $test_name() {
+            let mut dev = new(&[I2cTrans::write(
+                Address::COMMAND,
+                vec![DEFAULT_CMD | $expected << 2],
+            )]);
+            block_on(dev.set_integration_time($it)).unwrap();
+            destroy(dev);
+        }
+    };
+}
+
+it_test!(can_set_integration_time_half_t, IntegrationTime::HalfT, 0);
+it_test!(can_set_integration_time_1_t, IntegrationTime::T1, 1);
+it_test!(can_set_integration_time_2_t, IntegrationTime::T2, 2);
+it_test!(can_set_integration_time_4_t, IntegrationTime::T4, 3);
+
+#[test]
+fn can_apply_config() {
+    let mut dev = new(&[I2cTrans::write(Address::COMMAND, vec![0b0011_1111])]);
+    let config = Config::new()
+        .integration_time(IntegrationTime::T4)
+        .ack(true)
+        .ack_threshold(AckThreshold::Steps145)
+        .shutdown(true);
+    block_on(dev.apply_config(&config)).unwrap();
+    destroy(dev);
+}
+
+#[test]
+fn can_measure() {
+    let mut dev = new(&[
+        I2cTrans::write(Address::COMMAND, vec![DEFAULT_CMD]),
+        I2cTrans::read(Address::DATA_MSB, vec![0xAB]),
+        I2cTrans::read(Address::DATA_LSB, vec![0xCD]),
+        I2cTrans::write(Address::COMMAND, vec![DEFAULT_CMD | 1]),
+    ]);
+    let mut delay = NoopDelay;
+    let reading = block_on(dev.measure(&mut delay)).unwrap();
+    assert_eq!(0xABCD, reading);
+    destroy(dev);
+}
+
+#[test]
+fn can_read_uv_risk_level() {
+    let mut dev = new(&[
+        I2cTrans::read(Address::DATA_MSB, vec![0x01]),
+        I2cTrans::read(Address::DATA_LSB, vec![0x2C]),
+    ]);
+    let risk_level = block_on(dev.read_uv_risk_level()).unwrap();
+    assert_eq!(RiskLevel::Moderate, risk_level);
+    destroy(dev);
+}
@@ -1,5 +1,8 @@
-use embedded_hal_mock::i2c::{Mock as I2cMock, Transaction as I2cTrans};
-use veml6070::{AckThreshold, IntegrationTime, Veml6070};
+#![cfg(not(feature = "async"))]
+
+use embedded_hal_mock::eh0::delay::NoopDelay as DelayMock;
+use embedded_hal_mock::eh0::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+use veml6070::{get_uva_risk_level, AckThreshold, Config, IntegrationTime, RiskLevel, Veml6070};
 
 struct Address;
 
@@ -10,6 +13,7 @@ impl Address {
     const DATA_LSB: u8 = 0x38;
 }
 const DEFAULT_CMD: u8 = 0x02;
+const DEFAULT_RSET_OHMS: u32 = 270_000;
 
 fn new(transactions: &[I2cTrans]) -> Veml6070<I2cMock> {
     Veml6070::new(I2cMock::new(transactions))
@@ -124,3 +128,139 @@ fn can_enable_ack_with_threshold_145_steps() {
         .unwrap();
     destroy(dev);
 }
+
+macro_rules! risk_level_test {
+    ( $test_name:ident, $raw:expr, $it:expr, $rset_ohms:expr, $expected:expr ) => {
+        #[test]
+        fn $test_name() {
+            assert_eq!($expected, get_uva_risk_level($raw, $it, $rset_ohms));
+        }
+    };
+}
+
+risk_level_test!(
+    risk_level_low_1t,
+    560,
+    IntegrationTime::T1,
+    DEFAULT_RSET_OHMS,
+    RiskLevel::Low
+);
+risk_level_test!(
+    risk_level_moderate_1t,
+    561,
+    IntegrationTime::T1,
+    DEFAULT_RSET_OHMS,
+    RiskLevel::Moderate
+);
+risk_level_test!(
+    risk_level_high_1t,
+    1121,
+    IntegrationTime::T1,
+    DEFAULT_RSET_OHMS,
+    RiskLevel::High
+);
+risk_level_test!(
+    risk_level_very_high_1t,
+    1495,
+    IntegrationTime::T1,
+    DEFAULT_RSET_OHMS,
+    RiskLevel::VeryHigh
+);
+risk_level_test!(
+    risk_level_extreme_1t,
+    2055,
+    IntegrationTime::T1,
+    DEFAULT_RSET_OHMS,
+    RiskLevel::Extreme
+);
+risk_level_test!(
+    risk_level_scales_with_half_t,
+    280,
+    IntegrationTime::HalfT,
+    DEFAULT_RSET_OHMS,
+    RiskLevel::Low
+);
+risk_level_test!(
+    risk_level_scales_with_4t,
+    2055 * 4,
+    IntegrationTime::T4,
+    DEFAULT_RSET_OHMS,
+    RiskLevel::Extreme
+);
+risk_level_test!(
+    risk_level_scales_with_rset,
+    2000,
+    IntegrationTime::T1,
+    DEFAULT_RSET_OHMS * 2,
+    RiskLevel::Moderate
+);
+
+#[test]
+fn get_uva_risk_level_does_not_panic_on_zero_rset() {
+    // `rset_ohms` is clamped to a minimum of `1` instead of panicking.
+    let _ = get_uva_risk_level(1, IntegrationTime::T1, 0);
+}
+
+#[test]
+fn can_apply_config() {
+    let mut dev = new(&[I2cTrans::write(Address::COMMAND, vec![0b0011_1111])]);
+    let config = Config::new()
+        .integration_time(IntegrationTime::T4)
+        .ack(true)
+        .ack_threshold(AckThreshold::Steps145)
+        .shutdown(true);
+    dev.apply_config(&config).unwrap();
+    destroy(dev);
+}
+
+#[test]
+fn applying_default_config_writes_default_cmd() {
+    let mut dev = new(&[I2cTrans::write(Address::COMMAND, vec![DEFAULT_CMD])]);
+    dev.apply_config(&Config::new()).unwrap();
+    destroy(dev);
+}
+
+#[test]
+fn can_get_default_config() {
+    let dev = new(&[]);
+    assert_eq!(Config::new(), dev.get_config());
+    destroy(dev);
+}
+
+#[test]
+fn can_get_config_after_setters() {
+    let mut dev = new(&[I2cTrans::write(
+        Address::COMMAND,
+        vec![DEFAULT_CMD | 0b0010_0000],
+    )]);
+    dev.enable_ack().unwrap();
+    assert_eq!(Config::new().ack(true), dev.get_config());
+    destroy(dev);
+}
+
+#[test]
+fn can_measure() {
+    let mut dev = new(&[
+        I2cTrans::write(Address::COMMAND, vec![DEFAULT_CMD]),
+        I2cTrans::read(Address::DATA_MSB, vec![0xAB]),
+        I2cTrans::read(Address::DATA_LSB, vec![0xCD]),
+        I2cTrans::write(Address::COMMAND, vec![DEFAULT_CMD | 1]),
+    ]);
+    let mut delay = DelayMock::new();
+    let reading = dev.measure(&mut delay).unwrap();
+    assert_eq!(0xABCD, reading);
+    destroy(dev);
+}
+
+#[test]
+fn can_read_uv_risk_level() {
+    // Default integration time is `HalfT`, so the raw reading of 300 is
+    // normalized to 600 before classification.
+    let mut dev = new(&[
+        I2cTrans::read(Address::DATA_MSB, vec![0x01]),
+        I2cTrans::read(Address::DATA_LSB, vec![0x2C]),
+    ]);
+    let risk_level = dev.read_uv_risk_level().unwrap();
+    assert_eq!(RiskLevel::Moderate, risk_level);
+    destroy(dev);
+}